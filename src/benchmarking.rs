@@ -0,0 +1,143 @@
+//! Benchmarking setup for `pallet`.
+
+use super::*;
+use crate::Pallet as Did;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+    change_owner {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), new_owner.clone())
+    verify {
+        assert_eq!(OwnerOf::<T>::get(&caller), Some(new_owner));
+    }
+
+    add_delegate {
+        let d in 1 .. T::MaxDelegateTypeLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        let delegate_type = vec![7u8; d as usize];
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), delegate.clone(), delegate_type.clone(), Some(1_000u32.into()))
+    verify {
+        let bounded_delegate_type: BoundedVec<u8, T::MaxDelegateTypeLen> =
+            delegate_type.try_into().unwrap();
+        assert!(DelegateOf::<T>::contains_key((&caller, &bounded_delegate_type, &delegate)));
+    }
+
+    revoke_delegate {
+        let d in 1 .. T::MaxDelegateTypeLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        let delegate_type = vec![7u8; d as usize];
+        Did::<T>::create_delegate(&caller, &caller, &delegate, &delegate_type, Some(1_000u32.into()))?;
+    }: _(RawOrigin::Signed(caller.clone()), caller, delegate_type, delegate)
+
+    add_attribute {
+        let n in 1 .. T::MaxNameLen::get();
+        let v in 1 .. T::MaxValueLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let name = vec![1u8; n as usize];
+        let value = vec![2u8; v as usize];
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), name.clone(), value, None)
+    verify {
+        assert!(Did::<T>::attribute_and_id(&caller, &name).is_some());
+    }
+
+    revoke_attribute {
+        let n in 1 .. T::MaxNameLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let name = vec![1u8; n as usize];
+        Did::<T>::create_attribute(&caller, &caller, &name, &vec![2u8; 32], None)?;
+    }: _(RawOrigin::Signed(caller.clone()), caller, name)
+
+    delete_attribute {
+        let n in 1 .. T::MaxNameLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let name = vec![1u8; n as usize];
+        Did::<T>::create_attribute(&caller, &caller, &name, &vec![2u8; 32], None)?;
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), name.clone())
+    verify {
+        assert!(Did::<T>::attribute_and_id(&caller, &name).is_none());
+    }
+
+    execute {
+        // The signer must equal the identity to hit the owner fast-path
+        // without first registering a delegate, so the identity/caller is
+        // whatever account the signing helper controls. `validity` must be
+        // non-zero so `signed_attribute` takes the create path rather than
+        // trying to reset an attribute that doesn't exist yet.
+        let n in 1 .. T::MaxNameLen::get();
+        let v in 1 .. T::MaxValueLen::get();
+        let name = vec![1u8; n as usize];
+        let value = vec![2u8; v as usize];
+        let validity = 1_000u32;
+
+        let identity = T::BenchmarkHelper::signer();
+        let nonce = Nonce::<T>::get(&identity);
+        let mut encoded = name.encode();
+        encoded.extend(value.encode());
+        encoded.extend(validity.encode());
+        encoded.extend(identity.encode());
+        encoded.extend(nonce.encode());
+        let signature = T::BenchmarkHelper::sign(&encoded);
+
+        let transaction = AttributeTransaction {
+            signature,
+            name,
+            value,
+            validity,
+            signer: identity.clone(),
+            identity: identity.clone(),
+            nonce,
+        };
+    }: _(RawOrigin::Signed(identity), transaction)
+
+    revoke_credential {
+        let caller: T::AccountId = whitelisted_caller();
+        let credential_hash = [9u8; 32];
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), credential_hash)
+    verify {
+        assert!(Did::<T>::is_revoked(&caller, &credential_hash));
+    }
+
+    unrevoke_credential {
+        let caller: T::AccountId = whitelisted_caller();
+        let credential_hash = [9u8; 32];
+        Did::<T>::do_revoke_credential(&caller, &caller, &credential_hash)?;
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), credential_hash)
+    verify {
+        assert!(!Did::<T>::is_revoked(&caller, &credential_hash));
+    }
+
+    execute_revocation {
+        // As with `execute`, the signer must equal the issuer to hit the
+        // owner fast-path, so the issuer is whatever account the signing
+        // helper controls.
+        let credential_hash = [9u8; 32];
+
+        let identity = T::BenchmarkHelper::signer();
+        let nonce = Nonce::<T>::get(&identity);
+        let mut encoded = identity.encode();
+        encoded.extend(credential_hash.encode());
+        encoded.extend(true.encode());
+        encoded.extend(nonce.encode());
+        let signature = T::BenchmarkHelper::sign(&encoded);
+
+        let transaction = CredentialRevocationTransaction {
+            signature,
+            issuer: identity.clone(),
+            credential_hash,
+            revoked: true,
+            signer: identity.clone(),
+            nonce,
+        };
+    }: _(RawOrigin::Signed(identity.clone()), transaction)
+    verify {
+        assert!(Did::<T>::is_revoked(&identity, &credential_hash));
+    }
+}
@@ -2,10 +2,10 @@
 //! pallet, independently of its storage layout.
 
 use crate::types::AttributedId;
-use frame_support::dispatch::DispatchResult;
+use frame_support::{dispatch::DispatchResult, pallet_prelude::Get};
 
 /// Identity management, delegation and attribute handling for a DID pallet.
-pub trait Did<AccountId, BlockNumber, Moment, Signature> {
+pub trait Did<AccountId, BlockNumber, Moment, Signature, MaxNameLen: Get<u32>, MaxValueLen: Get<u32>> {
     /// Validates if the AccountId 'actual_owner' owns the identity.
     fn is_owner(identity: &AccountId, actual_owner: &AccountId) -> DispatchResult;
 
@@ -67,5 +67,5 @@ pub trait Did<AccountId, BlockNumber, Moment, Signature> {
     fn attribute_and_id(
         identity: &AccountId,
         name: &[u8],
-    ) -> Option<AttributedId<BlockNumber, Moment>>;
+    ) -> Option<AttributedId<BlockNumber, Moment, MaxNameLen, MaxValueLen>>;
 }
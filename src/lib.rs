@@ -6,14 +6,39 @@ use crate::types::*;
 pub use pallet::*;
 
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::Time};
-use frame_system::ensure_signed;
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    pallet_prelude::*,
+    traits::{StorageVersion, Time},
+    weights::Weight,
+};
+use frame_system::{ensure_none, ensure_signed};
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{IdentifyAccount, Member, Verify};
+use sp_runtime::traits::{IdentifyAccount, Member, ValidateUnsigned, Verify};
+use sp_runtime::transaction_validity::{
+    InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
+    ValidTransaction,
+};
 use sp_std::{prelude::*, vec::Vec};
 
+/// Reserved delegate type that lets its holder manage an identity's
+/// attributes (create/revoke/delete) without being the identity's
+/// owner/controller, enabling custodial or agent key setups.
+pub const ATTRIBUTE_MANAGER_DELEGATE_TYPE: &[u8] = b"sigAuth";
+
 pub mod did;
+pub mod migration;
+pub mod runtime_api;
 pub mod types;
+pub mod weights;
+
+/// The in-code storage version, bumped whenever a migration in
+/// [`migration`] is added. Kept in sync with `#[pallet::storage_version]`.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -21,13 +46,26 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub use weights::WeightInfo;
+
+/// Produces a signed identity and message signature for the `execute`
+/// benchmark, since the dispatchable's weight scales with the payload a
+/// relayer replays rather than with anything the pallet itself can derive.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+    /// The account id whose key `sign` signs with.
+    fn signer() -> AccountId;
+    /// Signs `message` with the key behind [`Self::signer`].
+    fn sign(message: &[u8]) -> Signature;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_system::pallet_prelude::*;
 
     #[pallet::pallet]
-    #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
@@ -37,6 +75,23 @@ pub mod pallet {
         type Public: IdentifyAccount<AccountId = Self::AccountId>;
         type Signature: Verify<Signer = Self::Public> + Member + Decode + Encode + TypeInfo;
         type Time: Time;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+        /// Helper for constructing a signed `execute` transaction in benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::Signature>;
+        /// Priority given to unsigned `execute` meta-transactions submitted by a relayer.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// Maximum length, in bytes, of an attribute name.
+        #[pallet::constant]
+        type MaxNameLen: Get<u32>;
+        /// Maximum length, in bytes, of an attribute value.
+        #[pallet::constant]
+        type MaxValueLen: Get<u32>;
+        /// Maximum length, in bytes, of a delegate type.
+        #[pallet::constant]
+        type MaxDelegateTypeLen: Get<u32>;
     }
 
     /// Identity delegates stored by type.
@@ -46,7 +101,7 @@ pub mod pallet {
         _,
         (
             NMapKey<Blake2_128Concat, T::AccountId>,
-            NMapKey<Blake2_128Concat, Vec<u8>>,
+            NMapKey<Blake2_128Concat, BoundedVec<u8, T::MaxDelegateTypeLen>>,
             NMapKey<Blake2_128Concat, T::AccountId>,
         ),
         T::BlockNumber,
@@ -62,20 +117,46 @@ pub mod pallet {
         T::AccountId,
         Blake2_128,
         [u8; 32],
-        Attribute<T::BlockNumber, <<T as Config>::Time as Time>::Moment>,
+        Attribute<T::BlockNumber, <<T as Config>::Time as Time>::Moment, T::MaxNameLen, T::MaxValueLen>,
         OptionQuery,
     >;
 
     /// Attribute nonce used to generate a unique hash even if the attribute is deleted and recreated.
     #[pallet::storage]
-    pub type AttributedNonce<T: Config> =
-        StorageDoubleMap<_, Blake2_128, T::AccountId, Blake2_128, Vec<u8>, u64, ValueQuery>;
+    pub type AttributedNonce<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128,
+        T::AccountId,
+        Blake2_128,
+        BoundedVec<u8, T::MaxNameLen>,
+        u64,
+        ValueQuery,
+    >;
 
     /// Identity owner.
     #[pallet::storage]
     pub type OwnerOf<T: Config> =
         StorageMap<_, Blake2_128, T::AccountId, T::AccountId, OptionQuery>;
 
+    /// Per-signer nonce guarding `execute` against replay of a previously valid
+    /// `AttributeTransaction`. Incremented every time a transaction is applied.
+    #[pallet::storage]
+    pub type Nonce<T: Config> = StorageMap<_, Blake2_128, T::AccountId, u64, ValueQuery>;
+
+    /// Revocation registry for verifiable credentials issued by an identity.
+    /// Maps `(issuer, credential_hash)` to the block at which the credential
+    /// was revoked; absence of an entry means the credential is not revoked.
+    #[pallet::storage]
+    pub type RevocationOf<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128,
+        T::AccountId,
+        Blake2_128,
+        [u8; 32],
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
     /// Tracking the latest identity update.
     #[pallet::storage]
     pub type UpdatedBy<T: Config> = StorageMap<
@@ -96,10 +177,13 @@ pub mod pallet {
         OwnerChanged(T::AccountId, T::AccountId, T::AccountId, T::BlockNumber),
         DelegateAdded(T::AccountId, Vec<u8>, T::AccountId, Option<T::BlockNumber>),
         DelegateRevoked(T::AccountId, Vec<u8>, T::AccountId),
-        AttributeAdded(T::AccountId, Vec<u8>, Option<T::BlockNumber>),
-        AttributeRevoked(T::AccountId, Vec<u8>, T::BlockNumber),
-        AttributeDeleted(T::AccountId, Vec<u8>, T::BlockNumber),
+        AttributeAdded(T::AccountId, T::AccountId, Vec<u8>, Option<T::BlockNumber>),
+        AttributeRevoked(T::AccountId, T::AccountId, Vec<u8>, T::BlockNumber),
+        AttributeDeleted(T::AccountId, T::AccountId, Vec<u8>, T::BlockNumber),
         AttributeTransactionExecuted(AttributeTransaction<T::Signature, T::AccountId>),
+        CredentialRevoked(T::AccountId, T::AccountId, [u8; 32], T::BlockNumber),
+        CredentialUnrevoked(T::AccountId, T::AccountId, [u8; 32]),
+        CredentialRevocationExecuted(CredentialRevocationTransaction<T::Signature, T::AccountId>),
     }
 
     #[pallet::error]
@@ -113,12 +197,14 @@ pub mod pallet {
         InvalidAttribute,
         Overflow,
         BadTransaction,
+        InvalidNonce,
+        CredentialNotRevoked,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::change_owner())]
         pub fn change_owner(
             origin: OriginFor<T>,
             identity: T::AccountId,
@@ -150,7 +236,7 @@ pub mod pallet {
 
         /// Adds a new delegate with an optional expiration period and specifies the delegate type.
         #[pallet::call_index(1)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::add_delegate(delegate_type.len() as u32))]
         pub fn add_delegate(
             origin: OriginFor<T>,
             identity: T::AccountId,
@@ -162,7 +248,10 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
 
             // Check if the delegate type is within the allowed length.
-            ensure!(delegate_type.len() <= 64, Error::<T>::InvalidDelegate);
+            ensure!(
+                delegate_type.len() as u32 <= T::MaxDelegateTypeLen::get(),
+                Error::<T>::InvalidDelegate
+            );
 
             // Create the delegate.
             Self::create_delegate(&who, &identity, &delegate, &delegate_type, valid_for)?;
@@ -185,7 +274,7 @@ pub mod pallet {
 
         /// Revokes a delegate for the specified identity by setting its expiration to the current block number.
         #[pallet::call_index(2)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::revoke_delegate(delegate_type.len() as u32))]
         pub fn revoke_delegate(
             origin: OriginFor<T>,
             identity: T::AccountId,
@@ -199,14 +288,18 @@ pub mod pallet {
             Self::is_owner(&identity, &who)?;
 
             // Validate the delegate type and ensure it's within the allowed length.
-            ensure!(delegate_type.len() <= 64, Error::<T>::InvalidDelegate);
+            ensure!(
+                delegate_type.len() as u32 <= T::MaxDelegateTypeLen::get(),
+                Error::<T>::InvalidDelegate
+            );
+            let bounded_delegate_type = Self::bounded_delegate_type(&delegate_type)?;
 
             // Get the current timestamp and block number.
             let now_timestamp = T::Time::now();
             let now_block_number = <frame_system::Pallet<T>>::block_number();
 
             // Update only the validity period to revoke the delegate.
-            <DelegateOf<T>>::mutate((&identity, &delegate_type, &delegate), |b| {
+            <DelegateOf<T>>::mutate((&identity, &bounded_delegate_type, &delegate), |b| {
                 *b = Some(now_block_number)
             });
 
@@ -224,7 +317,7 @@ pub mod pallet {
         /// Creates a new attribute as part of an identity.
         /// Sets its expiration period.
         #[pallet::call_index(3)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::add_attribute(name.len() as u32, value.len() as u32))]
         pub fn add_attribute(
             origin: OriginFor<T>,
             identity: T::AccountId,
@@ -233,28 +326,39 @@ pub mod pallet {
             valid_for: Option<T::BlockNumber>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(name.len() <= 64, Error::<T>::AttributeCreationFailed);
+            ensure!(
+                name.len() as u32 <= T::MaxNameLen::get(),
+                Error::<T>::AttributeCreationFailed
+            );
+            ensure!(
+                value.len() as u32 <= T::MaxValueLen::get(),
+                Error::<T>::AttributeCreationFailed
+            );
 
             Self::create_attribute(&who, &identity, &name, &value, valid_for)?;
-            Self::deposit_event(Event::AttributeAdded(identity, name, valid_for));
+            Self::deposit_event(Event::AttributeAdded(identity, who, name, valid_for));
             Ok(())
         }
 
         /// Revokes an attribute/property from an identity.
         /// Sets its expiration period to the actual block number.
         #[pallet::call_index(4)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::revoke_attribute(name.len() as u32))]
         pub fn revoke_attribute(
             origin: OriginFor<T>,
             identity: T::AccountId,
             name: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(name.len() <= 64, Error::<T>::AttributeRemovalFailed);
+            ensure!(
+                name.len() as u32 <= T::MaxNameLen::get(),
+                Error::<T>::AttributeRemovalFailed
+            );
 
-            Self::reset_attribute(who, &identity, &name)?;
+            Self::reset_attribute(who.clone(), &identity, &name)?;
             Self::deposit_event(Event::AttributeRevoked(
                 identity,
+                who,
                 name,
                 <frame_system::Pallet<T>>::block_number(),
             ));
@@ -263,15 +367,27 @@ pub mod pallet {
 
         /// Removes an attribute from an identity. This attribute/property becomes unavailable.
         #[pallet::call_index(5)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::delete_attribute(name.len() as u32))]
         pub fn delete_attribute(
             origin: OriginFor<T>,
             identity: T::AccountId,
             name: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            Self::is_owner(&identity, &who)?;
-            ensure!(name.len() <= 64, Error::<T>::AttributeRemovalFailed);
+            ensure!(
+                Self::is_owner(&identity, &who).is_ok()
+                    || Self::valid_listed_delegate(
+                        &identity,
+                        ATTRIBUTE_MANAGER_DELEGATE_TYPE,
+                        &who
+                    )
+                    .is_ok(),
+                Error::<T>::NotOwner
+            );
+            ensure!(
+                name.len() as u32 <= T::MaxNameLen::get(),
+                Error::<T>::AttributeRemovalFailed
+            );
 
             let now_block_number = <frame_system::Pallet<T>>::block_number();
             let result = Self::attribute_and_id(&identity, &name);
@@ -283,35 +399,119 @@ pub mod pallet {
 
             <UpdatedBy<T>>::insert(&identity, (&who, &now_block_number, T::Time::now()));
 
-            Self::deposit_event(Event::AttributeDeleted(identity, name, now_block_number));
+            Self::deposit_event(Event::AttributeDeleted(
+                identity,
+                who,
+                name,
+                now_block_number,
+            ));
             Ok(())
         }
 
-        /// Executes off-chain signed transaction.
+        /// Executes an off-chain signed `AttributeTransaction`.
+        ///
+        /// Accepts either a signed origin (any fee-paying account may relay the
+        /// transaction) or an unsigned origin, in which case
+        /// [`Pallet::validate_unsigned`] is what authorizes it. Either way, the
+        /// state change is authorized by `transaction.signature`, not by the
+        /// extrinsic's origin.
         #[pallet::call_index(6)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::execute(transaction.name.len() as u32, transaction.value.len() as u32))]
         pub fn execute(
             origin: OriginFor<T>,
             transaction: AttributeTransaction<T::Signature, T::AccountId>,
+        ) -> DispatchResult {
+            if ensure_none(origin.clone()).is_err() {
+                ensure_signed(origin)?;
+            }
+
+            Self::signed_attribute(&Self::attribute_transaction_message(&transaction), &transaction)?;
+            Self::deposit_event(Event::AttributeTransactionExecuted(transaction));
+            Ok(())
+        }
+
+        /// Revokes a credential issued by `issuer`, identified by its hash.
+        /// Callable by the issuer's owner or by a listed
+        /// `ATTRIBUTE_MANAGER_DELEGATE_TYPE` delegate.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::revoke_credential())]
+        pub fn revoke_credential(
+            origin: OriginFor<T>,
+            issuer: T::AccountId,
+            credential_hash: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_revoke_credential(&issuer, &who, &credential_hash)
+        }
+
+        /// Unrevokes a previously revoked credential. Callable by the issuer's
+        /// owner or by a listed `ATTRIBUTE_MANAGER_DELEGATE_TYPE` delegate.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::unrevoke_credential())]
+        pub fn unrevoke_credential(
+            origin: OriginFor<T>,
+            issuer: T::AccountId,
+            credential_hash: [u8; 32],
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::do_unrevoke_credential(&issuer, &who, &credential_hash)
+        }
 
-            let mut encoded = transaction.name.encode();
-            encoded.extend(transaction.value.encode());
-            encoded.extend(transaction.validity.encode());
-            encoded.extend(transaction.identity.encode());
+        /// Executes an off-chain signed `CredentialRevocationTransaction`.
+        ///
+        /// Accepts either a signed origin (any fee-paying account may relay the
+        /// transaction) or an unsigned origin, in which case
+        /// [`Pallet::validate_unsigned`] is what authorizes it. Either way, the
+        /// state change is authorized by `transaction.signature`, not by the
+        /// extrinsic's origin.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::execute_revocation())]
+        pub fn execute_revocation(
+            origin: OriginFor<T>,
+            transaction: CredentialRevocationTransaction<T::Signature, T::AccountId>,
+        ) -> DispatchResult {
+            if ensure_none(origin.clone()).is_err() {
+                ensure_signed(origin)?;
+            }
 
-            // Execute the storage update if the signer is valid.
-            Self::signed_attribute(who, &encoded, &transaction)?;
-            Self::deposit_event(Event::AttributeTransactionExecuted(transaction));
+            Self::signed_revoke_credential(
+                &Self::credential_revocation_message(&transaction),
+                &transaction,
+            )?;
+            Self::deposit_event(Event::CredentialRevocationExecuted(transaction));
             Ok(())
         }
     }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Lets a relayer submit a signed [`AttributeTransaction`] or
+        /// [`CredentialRevocationTransaction`] as an unsigned extrinsic: the
+        /// signature and nonce play the role `ensure_signed` would normally
+        /// play, so no fee-paying origin is required.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::execute { transaction } => Self::validate_attribute_transaction(transaction),
+                Call::execute_revocation { transaction } => {
+                    Self::validate_revocation_transaction(transaction)
+                }
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
 }
 
 impl<T: Config>
-    Did<T::AccountId, T::BlockNumber, <<T as Config>::Time as Time>::Moment, T::Signature>
-    for Pallet<T>
+    Did<
+        T::AccountId,
+        T::BlockNumber,
+        <<T as Config>::Time as Time>::Moment,
+        T::Signature,
+        T::MaxNameLen,
+        T::MaxValueLen,
+    > for Pallet<T>
 {
     /// Validates if the AccountId 'actual_owner' owns the identity.
     fn is_owner(identity: &T::AccountId, actual_owner: &T::AccountId) -> DispatchResult {
@@ -334,7 +534,10 @@ impl<T: Config>
         delegate_type: &[u8],
         delegate: &T::AccountId,
     ) -> DispatchResult {
-        ensure!(delegate_type.len() <= 64, Error::<T>::InvalidDelegate);
+        ensure!(
+            delegate_type.len() as u32 <= T::MaxDelegateTypeLen::get(),
+            Error::<T>::InvalidDelegate
+        );
         ensure!(
             Self::valid_listed_delegate(identity, delegate_type, delegate).is_ok()
                 || Self::is_owner(identity, delegate).is_ok(),
@@ -349,12 +552,13 @@ impl<T: Config>
         delegate_type: &[u8],
         delegate: &T::AccountId,
     ) -> DispatchResult {
+        let delegate_type = Self::bounded_delegate_type(delegate_type)?;
         ensure!(
-            <DelegateOf<T>>::contains_key((&identity, delegate_type, &delegate)),
+            <DelegateOf<T>>::contains_key((&identity, &delegate_type, &delegate)),
             Error::<T>::InvalidDelegate
         );
 
-        let validity = <DelegateOf<T>>::get((identity, delegate_type, delegate));
+        let validity = <DelegateOf<T>>::get((identity, &delegate_type, delegate));
         match validity > Some(<frame_system::Pallet<T>>::block_number()) {
             true => Ok(()),
             false => Err(Error::<T>::InvalidDelegate.into()),
@@ -382,7 +586,8 @@ impl<T: Config>
             None => u32::max_value().into(),
         };
 
-        <DelegateOf<T>>::insert((&identity, delegate_type, delegate), &validity);
+        let bounded_delegate_type = Self::bounded_delegate_type(delegate_type)?;
+        <DelegateOf<T>>::insert((&identity, &bounded_delegate_type, delegate), &validity);
         Ok(())
     }
 
@@ -412,6 +617,7 @@ impl<T: Config>
     }
 
     /// Adds a new attribute to an identity and colects the storage fee.
+    /// Callable by the owner or by a listed `ATTRIBUTE_MANAGER_DELEGATE_TYPE` delegate.
     fn create_attribute(
         who: &T::AccountId,
         identity: &T::AccountId,
@@ -419,11 +625,19 @@ impl<T: Config>
         value: &[u8],
         valid_for: Option<T::BlockNumber>,
     ) -> DispatchResult {
-        Self::is_owner(identity, who)?;
+        ensure!(
+            Self::is_owner(identity, who).is_ok()
+                || Self::valid_listed_delegate(identity, ATTRIBUTE_MANAGER_DELEGATE_TYPE, who)
+                    .is_ok(),
+            Error::<T>::NotOwner
+        );
 
         if Self::attribute_and_id(identity, name).is_some() {
             Err(Error::<T>::AttributeCreationFailed.into())
         } else {
+            let bounded_name = Self::bounded_name(name)?;
+            let bounded_value = Self::bounded_value(value)?;
+
             let now_timestamp = T::Time::now();
             let now_block_number = <frame_system::Pallet<T>>::block_number();
             let validity: T::BlockNumber = match valid_for {
@@ -431,11 +645,11 @@ impl<T: Config>
                 None => u32::max_value().into(),
             };
 
-            let mut nonce = <AttributedNonce<T>>::get(identity, name.to_vec());
+            let mut nonce = <AttributedNonce<T>>::get(identity, &bounded_name);
             let id = (&identity, name, nonce).using_encoded(blake2_256);
             let new_attribute = Attribute {
-                name: name.to_vec(),
-                value: value.to_vec(),
+                name: bounded_name.clone(),
+                value: bounded_value,
                 validity,
                 creation: now_timestamp,
                 nonce,
@@ -444,15 +658,21 @@ impl<T: Config>
             // Prevent panic overflow
             nonce = nonce.checked_add(1).ok_or(Error::<T>::Overflow)?;
             <AttributeOf<T>>::insert(identity, id, new_attribute);
-            <AttributedNonce<T>>::insert(identity, name.to_vec(), nonce);
+            <AttributedNonce<T>>::insert(identity, &bounded_name, nonce);
             <UpdatedBy<T>>::insert(identity, (who, now_block_number, now_timestamp));
             Ok(())
         }
     }
 
     /// Updates the attribute validity to make it expire and invalid.
+    /// Callable by the owner or by a listed `ATTRIBUTE_MANAGER_DELEGATE_TYPE` delegate.
     fn reset_attribute(who: T::AccountId, identity: &T::AccountId, name: &[u8]) -> DispatchResult {
-        Self::is_owner(identity, &who)?;
+        ensure!(
+            Self::is_owner(identity, &who).is_ok()
+                || Self::valid_listed_delegate(identity, ATTRIBUTE_MANAGER_DELEGATE_TYPE, &who)
+                    .is_ok(),
+            Error::<T>::NotOwner
+        );
         // If the attribute contains_key, the latest valid block is set to the current block.
         let result = Self::attribute_and_id(identity, name);
         match result {
@@ -477,7 +697,10 @@ impl<T: Config>
 
     /// Validates if an attribute belongs to an identity and it has not expired.
     fn valid_attribute(identity: &T::AccountId, name: &[u8], value: &[u8]) -> DispatchResult {
-        ensure!(name.len() <= 64, Error::<T>::InvalidAttribute);
+        ensure!(
+            name.len() as u32 <= T::MaxNameLen::get(),
+            Error::<T>::InvalidAttribute
+        );
         let result = Self::attribute_and_id(identity, name);
 
         let (attr, _) = match result {
@@ -486,7 +709,7 @@ impl<T: Config>
         };
 
         if (attr.validity > (<frame_system::Pallet<T>>::block_number()))
-            && (attr.value == value.to_vec())
+            && (attr.value.as_slice() == value)
         {
             Ok(())
         } else {
@@ -499,8 +722,11 @@ impl<T: Config>
     fn attribute_and_id(
         identity: &T::AccountId,
         name: &[u8],
-    ) -> Option<AttributedId<T::BlockNumber, <<T as Config>::Time as Time>::Moment>> {
-        let nonce = <AttributedNonce<T>>::get(identity, name.to_vec());
+    ) -> Option<
+        AttributedId<T::BlockNumber, <<T as Config>::Time as Time>::Moment, T::MaxNameLen, T::MaxValueLen>,
+    > {
+        let bounded_name = Self::bounded_name(name).ok()?;
+        let nonce = <AttributedNonce<T>>::get(identity, &bounded_name);
 
         // Used for first time attribute creation
         let lookup_nonce = match nonce {
@@ -516,21 +742,244 @@ impl<T: Config>
 }
 
 impl<T: Config> Pallet<T> {
-    /// Creates a new attribute from a off-chain transaction.
+    /// Bounds a raw delegate type to [`Config::MaxDelegateTypeLen`].
+    pub(crate) fn bounded_delegate_type(
+        delegate_type: &[u8],
+    ) -> Result<BoundedVec<u8, T::MaxDelegateTypeLen>, DispatchError> {
+        delegate_type
+            .to_vec()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidDelegate.into())
+    }
+
+    /// Bounds a raw attribute name to [`Config::MaxNameLen`].
+    pub(crate) fn bounded_name(name: &[u8]) -> Result<BoundedVec<u8, T::MaxNameLen>, DispatchError> {
+        name.to_vec()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidAttribute.into())
+    }
+
+    /// Bounds a raw attribute value to [`Config::MaxValueLen`].
+    pub(crate) fn bounded_value(value: &[u8]) -> Result<BoundedVec<u8, T::MaxValueLen>, DispatchError> {
+        value
+            .to_vec()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidAttribute.into())
+    }
+
+    /// Returns whether `credential_hash`, issued by `issuer`, is currently
+    /// revoked.
+    pub fn is_revoked(issuer: &T::AccountId, credential_hash: &[u8; 32]) -> bool {
+        RevocationOf::<T>::contains_key(issuer, credential_hash)
+    }
+
+    /// Revokes a credential, recording the current block. Callable by the
+    /// issuer's owner or by a listed `ATTRIBUTE_MANAGER_DELEGATE_TYPE` delegate.
+    fn do_revoke_credential(
+        issuer: &T::AccountId,
+        who: &T::AccountId,
+        credential_hash: &[u8; 32],
+    ) -> DispatchResult {
+        ensure!(
+            Self::is_owner(issuer, who).is_ok()
+                || Self::valid_listed_delegate(issuer, ATTRIBUTE_MANAGER_DELEGATE_TYPE, who)
+                    .is_ok(),
+            Error::<T>::NotOwner
+        );
+
+        let now_block_number = <frame_system::Pallet<T>>::block_number();
+        <RevocationOf<T>>::insert(issuer, credential_hash, now_block_number);
+        Self::deposit_event(Event::CredentialRevoked(
+            issuer.clone(),
+            who.clone(),
+            *credential_hash,
+            now_block_number,
+        ));
+        Ok(())
+    }
+
+    /// Unrevokes a previously revoked credential. Callable by the issuer's
+    /// owner or by a listed `ATTRIBUTE_MANAGER_DELEGATE_TYPE` delegate.
+    fn do_unrevoke_credential(
+        issuer: &T::AccountId,
+        who: &T::AccountId,
+        credential_hash: &[u8; 32],
+    ) -> DispatchResult {
+        ensure!(
+            Self::is_owner(issuer, who).is_ok()
+                || Self::valid_listed_delegate(issuer, ATTRIBUTE_MANAGER_DELEGATE_TYPE, who)
+                    .is_ok(),
+            Error::<T>::NotOwner
+        );
+        ensure!(
+            <RevocationOf<T>>::contains_key(issuer, credential_hash),
+            Error::<T>::CredentialNotRevoked
+        );
+
+        <RevocationOf<T>>::remove(issuer, credential_hash);
+        Self::deposit_event(Event::CredentialUnrevoked(
+            issuer.clone(),
+            who.clone(),
+            *credential_hash,
+        ));
+        Ok(())
+    }
+
+    /// Builds the message a `CredentialRevocationTransaction` must be signed
+    /// over: the revocation payload plus the signer's expected nonce, so a
+    /// captured signature cannot be replayed once the nonce has moved on.
+    fn credential_revocation_message(
+        transaction: &CredentialRevocationTransaction<T::Signature, T::AccountId>,
+    ) -> Vec<u8> {
+        let mut encoded = transaction.issuer.encode();
+        encoded.extend(transaction.credential_hash.encode());
+        encoded.extend(transaction.revoked.encode());
+        encoded.extend(transaction.nonce.encode());
+        encoded
+    }
+
+    /// Revokes or unrevokes a credential from an off-chain signed
+    /// transaction. The transaction's embedded signature authorizes the
+    /// change; the acting account is always `transaction.signer`, never the
+    /// extrinsic's origin.
+    fn signed_revoke_credential(
+        encoded: &[u8],
+        transaction: &CredentialRevocationTransaction<T::Signature, T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(
+            transaction.nonce == <Nonce<T>>::get(&transaction.signer),
+            Error::<T>::InvalidNonce
+        );
+
+        Self::valid_signer(
+            &transaction.issuer,
+            &transaction.signature,
+            encoded,
+            &transaction.signer,
+        )?;
+
+        if transaction.revoked {
+            Self::do_revoke_credential(
+                &transaction.issuer,
+                &transaction.signer,
+                &transaction.credential_hash,
+            )?;
+        } else {
+            Self::do_unrevoke_credential(
+                &transaction.issuer,
+                &transaction.signer,
+                &transaction.credential_hash,
+            )?;
+        }
+
+        <Nonce<T>>::mutate(&transaction.signer, |nonce| {
+            *nonce = nonce.saturating_add(1)
+        });
+        Ok(())
+    }
+
+    /// Validates an unsigned `execute` extrinsic carrying an
+    /// `AttributeTransaction`.
+    fn validate_attribute_transaction(
+        transaction: &AttributeTransaction<T::Signature, T::AccountId>,
+    ) -> TransactionValidity {
+        if transaction.nonce != <Nonce<T>>::get(&transaction.signer) {
+            return InvalidTransaction::Stale.into();
+        }
+
+        let encoded = Self::attribute_transaction_message(transaction);
+        if Self::valid_signer(
+            &transaction.identity,
+            &transaction.signature,
+            &encoded,
+            &transaction.signer,
+        )
+        .is_err()
+        {
+            return InvalidTransaction::BadProof.into();
+        }
+
+        ValidTransaction::with_tag_prefix("DidAttributeTransaction")
+            .priority(T::UnsignedPriority::get())
+            .and_provides((transaction.signer.clone(), transaction.nonce))
+            .longevity(64)
+            .propagate(true)
+            .build()
+    }
+
+    /// Validates an unsigned `execute_revocation` extrinsic carrying a
+    /// `CredentialRevocationTransaction`.
+    fn validate_revocation_transaction(
+        transaction: &CredentialRevocationTransaction<T::Signature, T::AccountId>,
+    ) -> TransactionValidity {
+        if transaction.nonce != <Nonce<T>>::get(&transaction.signer) {
+            return InvalidTransaction::Stale.into();
+        }
+
+        let encoded = Self::credential_revocation_message(transaction);
+        if Self::valid_signer(
+            &transaction.issuer,
+            &transaction.signature,
+            &encoded,
+            &transaction.signer,
+        )
+        .is_err()
+        {
+            return InvalidTransaction::BadProof.into();
+        }
+
+        ValidTransaction::with_tag_prefix("DidCredentialRevocationTransaction")
+            .priority(T::UnsignedPriority::get())
+            .and_provides((transaction.signer.clone(), transaction.nonce))
+            .longevity(64)
+            .propagate(true)
+            .build()
+    }
+
+    /// Builds the message an `AttributeTransaction` must be signed over: the
+    /// attribute payload plus the signer's expected nonce, so a captured
+    /// signature cannot be replayed once the nonce has moved on.
+    fn attribute_transaction_message(
+        transaction: &AttributeTransaction<T::Signature, T::AccountId>,
+    ) -> Vec<u8> {
+        let mut encoded = transaction.name.encode();
+        encoded.extend(transaction.value.encode());
+        encoded.extend(transaction.validity.encode());
+        encoded.extend(transaction.identity.encode());
+        encoded.extend(transaction.nonce.encode());
+        encoded
+    }
+
+    /// Creates or revokes an attribute from an off-chain signed transaction.
+    /// The transaction's embedded signature authorizes the change; the acting
+    /// identity is always `transaction.signer`, never the extrinsic's origin.
     fn signed_attribute(
-        who: T::AccountId,
         encoded: &[u8],
         transaction: &AttributeTransaction<T::Signature, T::AccountId>,
     ) -> DispatchResult {
+        ensure!(
+            transaction.nonce == <Nonce<T>>::get(&transaction.signer),
+            Error::<T>::InvalidNonce
+        );
+
         // Verify that the Data was signed by the owner or a not expired signer delegate.
+        // This is the same predicate `validate_unsigned` checks before admitting an
+        // unsigned relayed transaction into the pool, so a delegate-signed
+        // transaction that passes validation cannot then fail dispatch here.
         Self::valid_signer(
             &transaction.identity,
             &transaction.signature,
             encoded,
             &transaction.signer,
         )?;
-        Self::is_owner(&transaction.identity, &transaction.signer)?;
-        ensure!(transaction.name.len() <= 64, Error::<T>::BadTransaction);
+        ensure!(
+            transaction.name.len() as u32 <= T::MaxNameLen::get(),
+            Error::<T>::BadTransaction
+        );
+        ensure!(
+            transaction.value.len() as u32 <= T::MaxValueLen::get(),
+            Error::<T>::BadTransaction
+        );
 
         let now_block_number = <frame_system::Pallet<T>>::block_number();
         let validity = now_block_number + transaction.validity.into();
@@ -539,15 +988,23 @@ impl<T: Config> Pallet<T> {
         // it will set the attribute latest valid block to the actual block.
         if validity > now_block_number {
             Self::create_attribute(
-                &who,
+                &transaction.signer,
                 &transaction.identity,
                 &transaction.name,
                 &transaction.value,
                 Some(transaction.validity.into()),
             )?;
         } else {
-            Self::reset_attribute(who, &transaction.identity, &transaction.name)?;
+            Self::reset_attribute(
+                transaction.signer.clone(),
+                &transaction.identity,
+                &transaction.name,
+            )?;
         }
+
+        <Nonce<T>>::mutate(&transaction.signer, |nonce| {
+            *nonce = nonce.saturating_add(1)
+        });
         Ok(())
     }
 }
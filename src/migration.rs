@@ -0,0 +1,130 @@
+//! Storage migration to the bounded storage introduced for `MaxEncodedLen`
+//! support (see [`crate::Config::MaxNameLen`], [`crate::Config::MaxValueLen`]
+//! and [`crate::Config::MaxDelegateTypeLen`]).
+//!
+//! `DelegateOf` and `AttributeOf` used to key/store raw `Vec<u8>`; they now
+//! use `BoundedVec<u8, _>` so the pallet can drop
+//! `#[pallet::without_storage_info]`. The two maps need different treatment:
+//!
+//! - `DelegateOf` is a `StorageNMap` hashed entirely with the reversible
+//!   `Blake2_128Concat`, so its pre-upgrade composite key can be decoded in
+//!   full through a `storage_alias` (see [`v0`]) and re-inserted bounded.
+//! - `AttributeOf`'s key ([u8; 32]) never changed; only the stored
+//!   `Attribute`'s `name`/`value` fields did, so `translate_values` rewrites
+//!   every entry in place without ever needing to decode a key.
+//! - `AttributedNonce`'s value (`u64`) never changed either, and its second
+//!   key is hashed with the non-reversible `Blake2_128` (see its
+//!   definition), so there is nothing to decode-and-rewrite there at all:
+//!   `BoundedVec<u8, N>` and `Vec<u8>` share an identical SCALE encoding, so
+//!   every existing entry already reads correctly through the new bounded
+//!   key type without migration.
+//!
+//! Entries that no longer fit the runtime's configured bounds are dropped
+//! (and logged) rather than panicking.
+
+use super::*;
+use frame_support::{
+    storage_alias,
+    traits::{OnRuntimeUpgrade, StorageVersion},
+    Blake2_128Concat,
+};
+
+/// The pre-migration shape of [`Attribute`], with unbounded `name`/`value`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+struct OldAttribute<BlockNumber, Moment> {
+    name: Vec<u8>,
+    value: Vec<u8>,
+    validity: BlockNumber,
+    creation: Moment,
+    nonce: u64,
+}
+
+/// The pre-migration storage layout, kept around only for this migration to
+/// read from. Every key component is hashed with `Blake2_128Concat`, so
+/// `v0::DelegateOf::<T>::iter()` can decode full composite keys.
+mod v0 {
+    use super::*;
+
+    #[storage_alias]
+    pub type DelegateOf<T: Config> = StorageNMap<
+        Pallet<T>,
+        (
+            NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>,
+            NMapKey<Blake2_128Concat, Vec<u8>>,
+            NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>,
+        ),
+        <T as frame_system::Config>::BlockNumber,
+        OptionQuery,
+    >;
+}
+
+/// Migrates [`DelegateOf`] and [`AttributeOf`] from unbounded `Vec<u8>`
+/// keys/fields to their `BoundedVec` equivalents, bumping the pallet's
+/// on-chain storage version from `0` to `1`.
+pub struct MigrateToBoundedStorage<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToBoundedStorage<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain_version = StorageVersion::get::<Pallet<T>>();
+        if onchain_version != 0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut reads = 1u64;
+        let mut writes = 0u64;
+
+        let stale_delegates: Vec<_> = v0::DelegateOf::<T>::iter().collect();
+        for ((identity, delegate_type, delegate), validity) in stale_delegates {
+            reads += 1;
+            match Self::bounded_delegate_type(&delegate_type) {
+                Ok(bounded) => {
+                    DelegateOf::<T>::insert((identity, bounded, delegate), validity);
+                    writes += 1;
+                }
+                Err(_) => {
+                    // `Vec<u8>` and `BoundedVec<u8, _>` encode identically, so
+                    // leaving this entry alone would leave it sitting under
+                    // the exact same storage key, just unreadable through the
+                    // bounded type from now on. Remove it outright instead.
+                    v0::DelegateOf::<T>::remove((identity, delegate_type, delegate));
+                    writes += 1;
+                    log::warn!(
+                        target: "runtime::did",
+                        "dropping DelegateOf entry with an oversized delegate_type during migration",
+                    );
+                }
+            }
+        }
+
+        AttributeOf::<T>::translate_values::<
+            OldAttribute<T::BlockNumber, <<T as Config>::Time as Time>::Moment>,
+            _,
+        >(|old| {
+            reads += 1;
+            match (Self::bounded_name(&old.name), Self::bounded_value(&old.value)) {
+                (Ok(name), Ok(value)) => {
+                    writes += 1;
+                    Some(Attribute {
+                        name,
+                        value,
+                        validity: old.validity,
+                        creation: old.creation,
+                        nonce: old.nonce,
+                    })
+                }
+                _ => {
+                    log::warn!(
+                        target: "runtime::did",
+                        "dropping AttributeOf entry with an oversized name/value during migration",
+                    );
+                    None
+                }
+            }
+        });
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+        writes += 1;
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
@@ -0,0 +1,88 @@
+//! Off-chain DID Document resolution.
+//!
+//! Exposes a [`DidResolverApi`] runtime API so RPC tooling can materialize a
+//! W3C-flavoured DID Document for an identity in a single call instead of
+//! replaying `OwnerOf`/`DelegateOf`/`AttributeOf` events off-chain.
+
+use crate::{did::Did, AttributeOf, Config, DelegateOf, Pallet};
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+/// The prefix an attribute's `name` must carry to be surfaced as a `service`
+/// endpoint rather than left out of the resolved document.
+pub const SERVICE_ATTRIBUTE_PREFIX: &[u8] = b"svc/";
+
+/// A verification relationship keyed by its delegate type
+/// (e.g. `x25519VerificationKey2018`).
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct VerificationMethod<AccountId> {
+    pub delegate_type: Vec<u8>,
+    pub controller: AccountId,
+}
+
+/// A service endpoint derived from a `svc/`-prefixed attribute.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ServiceEndpoint {
+    pub id: Vec<u8>,
+    pub endpoint: Vec<u8>,
+}
+
+/// A W3C-flavoured DID Document assembled from on-chain identity state.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DidDocument<AccountId> {
+    /// The identity's current controller, i.e. its `verificationMethod` owner.
+    pub controller: AccountId,
+    pub verification_methods: Vec<VerificationMethod<AccountId>>,
+    pub services: Vec<ServiceEndpoint>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Assembles the DID Document for `identity` as of the current block:
+    /// the controller from `OwnerOf`, every unexpired `DelegateOf` entry as a
+    /// verification relationship, and every unexpired attribute whose name
+    /// starts with [`SERVICE_ATTRIBUTE_PREFIX`] as a service endpoint.
+    /// Revoked or expired delegates/attributes are omitted.
+    pub fn resolve_did(identity: T::AccountId) -> DidDocument<T::AccountId> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let controller = Self::identity_owner(&identity);
+
+        let verification_methods = DelegateOf::<T>::iter_prefix((identity.clone(),))
+            .filter(|(_, validity)| *validity > now)
+            .map(|((delegate_type, delegate), _)| VerificationMethod {
+                delegate_type: delegate_type.into_inner(),
+                controller: delegate,
+            })
+            .collect();
+
+        let services = AttributeOf::<T>::iter_prefix_values(identity)
+            .filter_map(|attribute| {
+                if attribute.validity > now && attribute.name.starts_with(SERVICE_ATTRIBUTE_PREFIX)
+                {
+                    Some(ServiceEndpoint {
+                        id: attribute.name.into_inner(),
+                        endpoint: attribute.value.into_inner(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        DidDocument {
+            controller,
+            verification_methods,
+            services,
+        }
+    }
+}
+
+sp_api::decl_runtime_api! {
+    /// Lets off-chain tooling resolve a DID Document for an identity without
+    /// scraping `DelegateAdded`/`AttributeAdded`/... events.
+    pub trait DidResolverApi<AccountId> where AccountId: codec::Codec {
+        /// Assembles the DID Document for `identity` as of the current block.
+        fn resolve_did(identity: AccountId) -> DidDocument<AccountId>;
+    }
+}
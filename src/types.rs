@@ -7,17 +7,24 @@ use sp_std::vec::Vec;
 /// An attribute/property attached to an identity.
 /// Holds the raw name/value pair along with the block at which it expires
 /// and the timestamp it was created at.
-#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
-pub struct Attribute<BlockNumber, Moment> {
-    pub name: Vec<u8>,
-    pub value: Vec<u8>,
+///
+/// `name`/`value` are bounded so the pallet has `MaxEncodedLen` and can be
+/// used in runtimes that meter storage (PoV) weight.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNameLen, MaxValueLen))]
+pub struct Attribute<BlockNumber, Moment, MaxNameLen: Get<u32>, MaxValueLen: Get<u32>> {
+    pub name: BoundedVec<u8, MaxNameLen>,
+    pub value: BoundedVec<u8, MaxValueLen>,
     pub validity: BlockNumber,
     pub creation: Moment,
     pub nonce: u64,
 }
 
 /// An attribute together with the storage identifier it is keyed by.
-pub type AttributedId<BlockNumber, Moment> = (Attribute<BlockNumber, Moment>, [u8; 32]);
+pub type AttributedId<BlockNumber, Moment, MaxNameLen, MaxValueLen> = (
+    Attribute<BlockNumber, Moment, MaxNameLen, MaxValueLen>,
+    [u8; 32],
+);
 
 /// An off-chain signed transaction used to create or revoke an attribute
 /// without requiring the identity owner to submit it themselves.
@@ -29,4 +36,21 @@ pub struct AttributeTransaction<Signature, AccountId> {
     pub validity: u32,
     pub signer: AccountId,
     pub identity: AccountId,
+    /// The signer's expected current nonce, folded into the signed payload so a
+    /// previously valid transaction cannot be resubmitted once it has gone through.
+    pub nonce: u64,
+}
+
+/// An off-chain signed transaction used by an issuer to revoke or unrevoke a
+/// credential without submitting the extrinsic themselves.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CredentialRevocationTransaction<Signature, AccountId> {
+    pub signature: Signature,
+    pub issuer: AccountId,
+    pub credential_hash: [u8; 32],
+    pub revoked: bool,
+    pub signer: AccountId,
+    /// The signer's expected current nonce, folded into the signed payload so a
+    /// previously valid transaction cannot be resubmitted once it has gone through.
+    pub nonce: u64,
 }
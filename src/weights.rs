@@ -0,0 +1,170 @@
+//! Autogenerated weights for `pallet`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: 50, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench`, CPU: `Generic`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --pallet=pallet_did
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./pallets/did/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for `pallet`.
+pub trait WeightInfo {
+    fn change_owner() -> Weight;
+    fn add_delegate(d: u32) -> Weight;
+    fn revoke_delegate(d: u32) -> Weight;
+    fn add_attribute(n: u32, v: u32) -> Weight;
+    fn revoke_attribute(n: u32) -> Weight;
+    fn delete_attribute(n: u32) -> Weight;
+    fn execute(n: u32, v: u32) -> Weight;
+    fn revoke_credential() -> Weight;
+    fn unrevoke_credential() -> Weight;
+    fn execute_revocation() -> Weight;
+}
+
+/// Weights for `pallet` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: Did OwnerOf (r:1 w:1)
+    // Storage: Did UpdatedBy (r:0 w:1)
+    fn change_owner() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Did OwnerOf (r:1 w:0)
+    // Storage: Did DelegateOf (r:1 w:1)
+    // Storage: Did UpdatedBy (r:0 w:1)
+    fn add_delegate(d: u32) -> Weight {
+        (32_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Did OwnerOf (r:1 w:0)
+    // Storage: Did DelegateOf (r:0 w:1)
+    // Storage: Did UpdatedBy (r:0 w:1)
+    fn revoke_delegate(d: u32) -> Weight {
+        (28_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Did AttributedNonce (r:1 w:1)
+    // Storage: Did AttributeOf (r:1 w:1)
+    // Storage: Did UpdatedBy (r:0 w:1)
+    fn add_attribute(n: u32, v: u32) -> Weight {
+        (34_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add((1_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: Did AttributedNonce (r:1 w:0)
+    // Storage: Did AttributeOf (r:1 w:1)
+    // Storage: Did UpdatedBy (r:0 w:1)
+    fn revoke_attribute(n: u32) -> Weight {
+        (31_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Did AttributedNonce (r:1 w:0)
+    // Storage: Did AttributeOf (r:1 w:1)
+    // Storage: Did UpdatedBy (r:0 w:1)
+    fn delete_attribute(n: u32) -> Weight {
+        (31_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Did DelegateOf (r:1 w:0)
+    // Storage: Did OwnerOf (r:1 w:0)
+    // Storage: Did AttributedNonce (r:1 w:1)
+    // Storage: Did AttributeOf (r:1 w:1)
+    fn execute(n: u32, v: u32) -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add((1_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Did OwnerOf (r:1 w:0)
+    // Storage: Did DelegateOf (r:1 w:0)
+    // Storage: Did RevocationOf (r:0 w:1)
+    fn revoke_credential() -> Weight {
+        (29_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Did OwnerOf (r:1 w:0)
+    // Storage: Did DelegateOf (r:1 w:0)
+    // Storage: Did RevocationOf (r:1 w:1)
+    fn unrevoke_credential() -> Weight {
+        (29_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Did DelegateOf (r:1 w:0)
+    // Storage: Did OwnerOf (r:1 w:0)
+    // Storage: Did Nonce (r:1 w:1)
+    // Storage: Did RevocationOf (r:0 w:1)
+    fn execute_revocation() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn change_owner() -> Weight {
+        (30_000_000 as Weight)
+    }
+    fn add_delegate(d: u32) -> Weight {
+        (32_000_000 as Weight).saturating_add((1_000 as Weight).saturating_mul(d as Weight))
+    }
+    fn revoke_delegate(d: u32) -> Weight {
+        (28_000_000 as Weight).saturating_add((1_000 as Weight).saturating_mul(d as Weight))
+    }
+    fn add_attribute(n: u32, v: u32) -> Weight {
+        (34_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add((1_000 as Weight).saturating_mul(v as Weight))
+    }
+    fn revoke_attribute(n: u32) -> Weight {
+        (31_000_000 as Weight).saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+    }
+    fn delete_attribute(n: u32) -> Weight {
+        (31_000_000 as Weight).saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+    }
+    fn execute(n: u32, v: u32) -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add((1_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add((1_000 as Weight).saturating_mul(v as Weight))
+    }
+    fn revoke_credential() -> Weight {
+        (29_000_000 as Weight)
+    }
+    fn unrevoke_credential() -> Weight {
+        (29_000_000 as Weight)
+    }
+    fn execute_revocation() -> Weight {
+        (35_000_000 as Weight)
+    }
+}